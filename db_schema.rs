@@ -4,10 +4,10 @@
 // CREATE TYPE user_role_enum AS ENUM ('user', 'admin', 'moderator');
 // CREATE TYPE difficulty_level_enum AS ENUM ('Easy', 'Medium', 'Hard');
 // CREATE TYPE ai_provider_enum AS ENUM ('OpenAI', 'Google');
-// CREATE TYPE content_type_enum AS ENUM ('file', 'summary', 'quiz'); // Add other types as needed
+// CREATE TYPE content_type_enum AS ENUM ('file', 'summary', 'quiz', 'question'); // Add other types as needed
 // CREATE TYPE community_role_enum AS ENUM ('admin', 'member', 'moderator');
 // CREATE TYPE community_file_category_enum AS ENUM ('lecture', 'section', 'exam', 'summary_material', 'general_resource', 'other');
-// CREATE TYPE notification_type_enum AS ENUM ('new_content', 'comment_reply', 'quiz_result', 'community_invite', 'mention');
+// CREATE TYPE notification_type_enum AS ENUM ('new_content', 'comment_reply', 'quiz_result', 'community_invite', 'mention', 'new_follower', 'badge_awarded');
 // CREATE TYPE rating_value_enum AS ENUM ('1', '2', '3', '4', '5'); // For star ratings
 
 // --- Generic Trigger Function for updated_at (Define this in PostgreSQL) ---
@@ -87,6 +87,7 @@ Table mcq_question {
     
     // tag field removed, use mcq_question_tag_link join table for many-to-many
     user_id integer [ref: > user.id] // Creator of the question
+    normalized_text_hash varchar(64) [unique] // SHA256 hex of normalized question_text, used to dedupe bulk imports
 }
 
 // Join table for many-to-many relationship between mcq_question and question_tag
@@ -227,6 +228,20 @@ Table content_comment {
     created_at timestamp [default: `now()`]
     updated_at timestamp [default: `now()`] // Apply trigger
     // Index on (content_type, content_id) for faster comment retrieval
+    // comment_text is scanned for @username tokens on insert/update; see user_mention below
+}
+
+Table user_mention {
+    id integer [pk, autoincrement]
+    comment_id integer [ref: > content_comment.id, not null]
+    recipient_user_id integer [ref: > user.id, not null] // User tagged via @username in comment_text
+    is_read boolean [default: false]
+    created_at timestamp [default: `now()`]
+    Unique(comment_id, recipient_user_id)
+    // One row per distinct recipient per comment: @tokens are resolved to user.username case-insensitively (tokens that
+    // differ only in case, e.g. @Bob and @bob, yield a single row), and the comment author is excluded.
+    // On edit, the old and new mention sets are diffed so previously-notified recipients aren't re-notified;
+    // each newly-resolved recipient also gets a notification row with notification_type = 'mention'.
 }
 
 Table content_version {
@@ -360,4 +375,120 @@ Table user_preference {
 
     created_at timestamp [default: `now()`]
     updated_at timestamp [default: `now()`] // Apply trigger
+}
+
+// --- Social Graph Tables ---
+
+Table user_follow {
+    follower_id integer [ref: > user.id, not null, check: "follower_id <> followee_id"] // The user doing the following
+    followee_id integer [ref: > user.id, not null] // The user being followed
+    created_at timestamp [default: `now()`]
+    show_in_timeline boolean [default: true] // Allows following without surfacing the followee's activity in the feed
+    Primary Key(follower_id, followee_id)
+    // Inserting a row here also emits a notification (notification_type = 'new_follower') to followee_id.
+    // Timeline for a viewer unions recent public summary / mcq_quiz / community_subject_file rows authored by
+    // followees with show_in_timeline = true, respecting is_public/is_private visibility, ordered by created_at
+    // with keyset pagination on (created_at, id).
+}
+
+// --- Gamification Tables ---
+
+Table badge {
+    id integer [pk, autoincrement]
+    name varchar(100) [not null, unique]
+    description text
+    icon_url varchar(255)
+    badge_type varchar(20) [not null] // 'one_time', 'repeatable', 'tiered'
+    criteria_json jsonb [not null] // Award rule, e.g. {"type": "quiz_count", "count": 10} or {"type": "study_streak_days", "days": 7}
+    created_at timestamp [default: `now()`]
+    updated_at timestamp [default: `now()`] // Apply trigger
+}
+
+Table user_badge {
+    id integer [pk, autoincrement]
+    user_id integer [ref: > user.id, not null]
+    badge_id integer [ref: > badge.id, not null]
+    awarded_at timestamp [default: `now()`]
+    award_count integer [default: 1] // Incremented on each re-match for repeatable badges
+    Unique(user_id, badge_id)
+    // Awarding is evaluated after quiz_session.is_completed flips true, summary creation, and first community
+    // contribution. A one-time badge's row is only ever inserted once per user (idempotent replay); a
+    // repeatable badge bumps award_count instead of inserting a duplicate row. First award also emits a
+    // notification with notification_type = 'badge_awarded'.
+}
+
+// --- Search Tables ---
+
+Table search_index {
+    // Polymorphic association:
+    content_type content_type_enum [not null] // e.g., 'summary', 'quiz', 'question'
+    content_id integer [not null]
+
+    search_vector tsvector [not null] // to_tsvector('english', ...): title/question_text weighted A, body/explanation weighted B
+    // Kept in sync by triggers on summary (full_markdown/title), mcq_question (question_text/explanation),
+    // and mcq_quiz (title/description) insert/update.
+
+    created_at timestamp [default: `now()`]
+    updated_at timestamp [default: `now()`] // Apply trigger
+
+    Primary Key(content_type, content_id)
+    // GIN index on search_vector for ts_rank_cd ordered lookups, e.g.:
+    // CREATE INDEX idx_search_index_vector ON search_index USING GIN (search_vector);
+    // Ranked search: plainto_tsquery/websearch_to_tsquery against search_vector, filtered by content_type and by
+    // the caller's visibility (own content, public content, communities via community_member), ordered by
+    // ts_rank_cd; snippets rendered with ts_headline against the underlying body field.
+}
+
+// --- Site Metrics Tables ---
+
+Table site_aggregate {
+    id integer [pk, autoincrement] // Single row table; application always reads/writes id = 1
+    registered_users integer [default: 0]
+    total_quizzes integer [default: 0]
+    total_summaries integer [default: 0]
+    total_communities integer [default: 0]
+    total_quiz_sessions integer [default: 0]
+    updated_at timestamp [default: `now()`] // Apply trigger
+    // Running totals kept current by triggers on insert into user, mcq_quiz, summary, community, quiz_session.
+}
+
+Table site_activity_daily {
+    activity_date date [pk]
+    registered_users integer [default: 0] // Cumulative registered users as of this date
+    active_day_users integer [default: 0] // Distinct user_id in user_session (or quiz_session.session_start) over trailing 1 day
+    active_week_users integer [default: 0] // Trailing 7 days
+    active_month_users integer [default: 0] // Trailing 30 days
+    active_halfyear_users integer [default: 0] // Trailing 180 days
+    total_quizzes integer [default: 0]
+    total_summaries integer [default: 0]
+    total_communities integer [default: 0]
+    total_quiz_sessions integer [default: 0]
+    created_at timestamp [default: `now()`]
+    // One row upserted per day by a scheduled job; exposed via an admin dashboard endpoint for engagement/growth reporting.
+}
+
+// --- MCQ Import Tables ---
+
+Table mcq_import_batch {
+    id integer [pk, autoincrement]
+    user_id integer [ref: > user.id, not null]
+    source_format varchar(30) [not null] // 'csv', 'aiken', 'gift', 'json', 'stackexchange_dump'
+    status varchar(20) [not null, default: 'pending'] // 'pending', 'processing', 'completed', 'failed'
+    created_at timestamp [default: `now()`]
+    updated_at timestamp [default: `now()`] // Apply trigger
+}
+
+Table mcq_import_row {
+    id integer [pk, autoincrement]
+    batch_id integer [ref: > mcq_import_batch.id, not null]
+    row_number integer [not null]
+    raw_payload jsonb [not null] // Source row as parsed from source_format before mapping to mcq_question shape
+    resolved_question_id integer [ref: > mcq_question.id] // Set once mapped/deduplicated into mcq_question
+    error_text text // Set instead of resolved_question_id when validation fails (e.g. missing correct option, empty stem)
+    created_at timestamp [default: `now()`]
+    Unique(batch_id, row_number)
+    // Deduplicated against existing mcq_question rows via mcq_question.normalized_text_hash.
+    // Source tags/categories auto-create question_tag rows and mcq_question_tag_link entries.
+    // A failing row never aborts the batch; the batch's mcq_import_row set doubles as the reviewable partial-import report.
+    // Successfully resolved rows can optionally be assembled into a new mcq_quiz via ordered mcq_quiz_question_link rows.
 }
\ No newline at end of file